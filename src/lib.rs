@@ -1,5 +1,5 @@
 use tracing::instrument;
-use tracing;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[instrument]
 pub fn add(left: usize, right: usize) -> usize {
@@ -7,6 +7,18 @@ pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+/// Shorten a title to at most `max` user-perceived characters (grapheme
+/// clusters) for progress and log output, appending "..." when cut.
+///
+/// Never splits a multi-byte character, combining sequence, or emoji.
+pub fn truncate_title(title: &str, max: usize) -> String {
+    let mut graphemes = title.grapheme_indices(true);
+    match graphemes.nth(max) {
+        Some((end, _)) => format!("{}...", &title[..end]),
+        None => title.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +28,36 @@ mod tests {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn truncate_title_short_is_unchanged() {
+        assert_eq!(truncate_title("Dune", 10), "Dune");
+        assert_eq!(truncate_title("Dune", 4), "Dune");
+        assert_eq!(truncate_title("", 0), "");
+    }
+
+    #[test]
+    fn truncate_title_ascii() {
+        assert_eq!(truncate_title("The Way of Kings", 7), "The Way...");
+    }
+
+    #[test]
+    fn truncate_title_multibyte() {
+        assert_eq!(truncate_title("Válka s mloky od Čapka", 14), "Válka s mloky ...");
+        assert_eq!(truncate_title("Čapek", 1), "Č...");
+    }
+
+    #[test]
+    fn truncate_title_cjk() {
+        assert_eq!(
+            truncate_title("本好きの下剋上 司書になるためには手段を選んでいられません", 7),
+            "本好きの下剋上..."
+        );
+    }
+
+    #[test]
+    fn truncate_title_emoji() {
+        assert_eq!(truncate_title("👨‍👩‍👧 Family 📚", 1), "👨‍👩‍👧...");
+        assert_eq!(truncate_title("🇯🇵🇨🇿 flags", 2), "🇯🇵🇨🇿...");
+    }
 }
@@ -1,7 +1,5 @@
 use clap::Parser;
-use kcci;
 
-use tracing;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 /// A simple CLI for the ki library